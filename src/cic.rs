@@ -27,7 +27,42 @@ impl From<io::Error> for CICError {
     }
 }
 
+/// Outcome of comparing a ROM's stored header CRCs against freshly computed ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+crate enum VerifyResult {
+    /// The stored and computed CRCs match.
+    Ok,
+
+    /// The stored and computed CRCs differ.
+    Mismatch { expected: (u32, u32), computed: (u32, u32) },
+
+    /// The ROM's CIC could not be identified, so no CRCs could be computed.
+    UnknownCic,
+
+    /// The ROM was too small to contain a header and program image to check.
+    TooSmall,
+}
+
+/// Byte order a ROM image's bootcode may be stored in.
+enum RomByteOrder {
+    /// `.z64`: big-endian, the order the rest of this module assumes.
+    BigEndian,
+
+    /// `.v64`: byteswapped 16 bits at a time.
+    ByteSwapped,
+
+    /// `.n64`: little-endian, swapped 32 bits at a time.
+    LittleEndian,
+}
+
 /// CIC definitions.
+///
+/// NUS-CIC-8303 (64DD), NUS-CIC-NDDJ/NDXJ (64DD development units), and
+/// NUS-CIC-5101/5167 (Aleck64-derived and 64DD-expansion boards) are real boot
+/// chips but are deliberately not represented here: nobody on this project has a
+/// genuine IPL dump or hardware-confirmed checksum seed for them, and shipping
+/// invented constants into a checksum tool is worse than not supporting the chip
+/// at all. Add them once real seeds/IPL CRC32s are available to confirm against.
 crate enum CIC {
     CIC6101([u8; IPL_SIZE]),
     CIC6102([u8; IPL_SIZE]),
@@ -75,10 +110,39 @@ impl CIC {
         let mut ipl = [0; IPL_SIZE];
         f.read_exact(&mut ipl)?;
 
-        // Check for known IPLs
+        Ok(Self::identify_ipl(ipl))
+    }
+
+    /// Detect the CIC used by a full ROM image, rather than a standalone IPL dump.
+    ///
+    /// The ROM may be in big-endian (`.z64`), byteswapped (`.v64`), or little-endian
+    /// (`.n64`) byte order; the bootcode is sliced out and normalized to big-endian
+    /// before being hashed.
+    crate fn from_rom(rom: &[u8]) -> Result<CIC, CICError> {
+        let byte_order = Self::detect_byte_order(rom)?;
+
+        let bootcode_start = 0x40;
+        let bootcode_end = bootcode_start + IPL_SIZE;
+        if rom.len() < bootcode_end {
+            Err(CICError::CICReadError(format!(
+                "ROM too small to contain bootcode: expected at least {} bytes, found {}",
+                bootcode_end,
+                rom.len()
+            )))?;
+        }
+
+        let mut ipl = [0; IPL_SIZE];
+        ipl.copy_from_slice(&rom[bootcode_start..bootcode_end]);
+        Self::normalize_byte_order(&mut ipl, byte_order);
+
+        Ok(Self::identify_ipl(ipl))
+    }
+
+    /// Hash an IPL dump against the known CRC32s and return the matching variant.
+    fn identify_ipl(ipl: [u8; IPL_SIZE]) -> CIC {
         let mut hasher = Hasher::new();
         hasher.update(&ipl);
-        let cic = match hasher.finalize() {
+        match hasher.finalize() {
             0x6170a4a1 => CIC::CIC6101(ipl),
             0x90bb6cb5 => CIC::CIC6102(ipl),
             0x0b050ee0 => CIC::CIC6103(ipl),
@@ -86,9 +150,47 @@ impl CIC {
             0xacc8580a => CIC::CIC6106(ipl),
             0x009e9ea3 => CIC::CIC7102(ipl),
             _ => CIC::UNKNOWN(ipl),
-        };
+        }
+    }
 
-        Ok(cic)
+    /// Detect a ROM's byte order from its header magic.
+    fn detect_byte_order(rom: &[u8]) -> Result<RomByteOrder, CICError> {
+        if rom.len() < 4 {
+            Err(CICError::CICReadError("ROM too small to contain a header".to_string()))?;
+        }
+
+        match &rom[0..4] {
+            // .z64: already big-endian
+            [0x80, 0x37, 0x12, 0x40] => Ok(RomByteOrder::BigEndian),
+            // .v64: byteswapped 16 bits at a time
+            [0x37, 0x80, 0x40, 0x12] => Ok(RomByteOrder::ByteSwapped),
+            // .n64: little-endian, swapped 32 bits at a time
+            [0x40, 0x12, 0x37, 0x80] => Ok(RomByteOrder::LittleEndian),
+            magic => Err(CICError::CICReadError(format!("Unrecognized ROM header magic: {:02x?}", magic)))?,
+        }
+    }
+
+    /// Normalize a buffer from the given byte order to big-endian, in place.
+    ///
+    /// Only ever called on the bootcode slice (a fixed `IPL_SIZE`, always a
+    /// multiple of 4 bytes), not the whole ROM, so detecting and normalizing a
+    /// multi-megabyte ROM never costs more than the ~4 KiB actually needed.
+    /// `chunks_exact_mut` also means a buffer whose length isn't a multiple of the
+    /// swap width is left with an untouched trailing remainder instead of panicking.
+    fn normalize_byte_order(buf: &mut [u8], byte_order: RomByteOrder) {
+        match byte_order {
+            RomByteOrder::BigEndian => {}
+            RomByteOrder::ByteSwapped => {
+                for pair in buf.chunks_exact_mut(2) {
+                    pair.swap(0, 1);
+                }
+            }
+            RomByteOrder::LittleEndian => {
+                for word in buf.chunks_exact_mut(4) {
+                    word.reverse();
+                }
+            }
+        }
     }
 
     crate fn get_ipl(&self) -> &[u8; IPL_SIZE] {
@@ -187,6 +289,97 @@ impl CIC {
         (crc1.0, crc2.0)
     }
 
+    /// Compute CRCs for many independent ROMs in parallel, collecting the results
+    /// in input order.
+    ///
+    /// `compute_crcs` itself is inherently sequential (its accumulators carry state
+    /// word-to-word), but separate ROMs have nothing to do with each other, so this
+    /// runs each job on its own scoped thread instead. There is no pool: one thread
+    /// is spawned per job, and the number of threads is not capped.
+    crate fn compute_crcs_batch(jobs: &[(CIC, &[u8], &[u8])]) -> Vec<(u32, u32)> {
+        let mut results = vec![(0, 0); jobs.len()];
+
+        std::thread::scope(|scope| {
+            for (result, (cic, program, fs)) in results.iter_mut().zip(jobs) {
+                scope.spawn(move || *result = cic.compute_crcs(program, fs));
+            }
+        });
+
+        results
+    }
+
+    /// Identify which CIC a ROM was built for from its program/fs data and the
+    /// `(crc1, crc2)` pair stored in its header, without needing an IPL dump.
+    ///
+    /// Every variant whose checksum algorithm doesn't depend on a real IPL is tried
+    /// in turn, and the first whose `compute_crcs` output matches `expected` is
+    /// returned. CIC6101, CIC6102, and CIC7102 share an identical algorithm and so
+    /// are indistinguishable from CRCs alone; CIC6102 is returned as the canonical
+    /// representative of that group.
+    ///
+    /// CIC6105 is never matched here: its algorithm mixes in a 64-word table hidden
+    /// in the real IPL (see `compute_crcs`), which this function has no access to.
+    /// A zero-filled placeholder IPL would not reproduce the real CRCs, so 6105 ROMs
+    /// will correctly fail to match rather than falsely report a different variant.
+    crate fn identify_from_crcs(program: &[u8], fs: &[u8], expected: (u32, u32)) -> Option<CIC> {
+        let candidates: Vec<fn([u8; IPL_SIZE]) -> CIC> = vec![CIC::CIC6102, CIC::CIC6103, CIC::CIC6106];
+
+        for make_cic in candidates {
+            let cic = make_cic([0; IPL_SIZE]);
+            if cic.compute_crcs(program, fs) == expected {
+                return Some(cic);
+            }
+        }
+
+        None
+    }
+
+    /// Recompute the CRCs for a ROM's program image and compare them against the
+    /// CRC1/CRC2 words stored in its header (big-endian, at offsets 0x10 and 0x14).
+    ///
+    /// The program image is assumed to start at 0x1000, immediately after the boot
+    /// block, matching the layout `compute_crcs` expects. This subsystem exists to
+    /// audit ROMs that may be corrupted or truncated, so a too-small ROM is expected
+    /// input: it's reported as `VerifyResult::TooSmall` rather than panicking.
+    crate fn verify_rom(&self, rom: &[u8]) -> VerifyResult {
+        if let CIC::UNKNOWN(_) = self {
+            return VerifyResult::UnknownCic;
+        }
+
+        if rom.len() < 0x1000 {
+            return VerifyResult::TooSmall;
+        }
+
+        let expected = (BigEndian::read_u32(&rom[0x10..0x14]), BigEndian::read_u32(&rom[0x14..0x18]));
+        let computed = self.compute_crcs(&rom[0x1000..], &[]);
+
+        if expected == computed {
+            VerifyResult::Ok
+        } else {
+            VerifyResult::Mismatch { expected, computed }
+        }
+    }
+
+    /// Recompute the CRCs for a ROM's program image and rewrite the header's
+    /// CRC1/CRC2 words in place, repairing a corrupted header or one left stale by
+    /// a patched program image.
+    crate fn repair_rom(&self, rom: &mut [u8]) -> Result<(), CICError> {
+        if rom.len() < 0x1000 {
+            Err(CICError::CICReadError(format!(
+                "ROM too small to repair: expected at least {} bytes, found {}",
+                0x1000,
+                rom.len()
+            )))?;
+        }
+
+        let (crc1, crc2) = self.compute_crcs(&rom[0x1000..], &[]);
+
+        BigEndian::write_u32(&mut rom[0x10..0x14], crc1);
+        BigEndian::write_u32(&mut rom[0x14..0x18], crc2);
+
+        Ok(())
+    }
+
     /// Offset the entry point for the current CIC
     crate fn offset(&self, entry_point: u32) -> u32 {
         entry_point + match self {
@@ -302,4 +495,5 @@ mod tests {
         let cic = CIC::CIC7102([0; IPL_SIZE]);
         assert_eq!(cic.offset(0x80000400), 0x80000400);
     }
+
 }